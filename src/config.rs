@@ -1,6 +1,7 @@
 use clap::builder::styling::{AnsiColor, Styles};
 
 pub const ROOT_DIR_NAME: &str = ".nous";
+pub const IGNORE_FILE_NAME: &str = ".nousignore";
 pub const DEFAULT_EXT: &str = "md";
 pub const SUPPORTED_EXTS: [&str; 5] = ["md", "markdown", "org", "txt", "text"];
 pub const FALLBACK_EDITOR: &str = if cfg!(windows) { "Notepad" } else { "vi" };
@@ -0,0 +1,161 @@
+use crate::config::ROOT_DIR_NAME;
+use crate::wikilinks::read_wikilinks;
+use crate::{node_from_path, realm_walker, try_relative_path, warn, WalkOptions};
+
+use rayon::iter::{ParallelBridge, ParallelIterator};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+const INDEX_FILE_NAME: &str = "index";
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IndexEntry {
+    pub relative_path: PathBuf,
+    pub mtime: SystemTime,
+    pub forward_links: Vec<String>,
+}
+
+pub type Index = BTreeMap<String, IndexEntry>;
+
+fn index_path(root: &Path) -> PathBuf {
+    root.join(ROOT_DIR_NAME).join(INDEX_FILE_NAME)
+}
+
+fn file_mtime(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+fn index_entry_for(root: &Path, path: &Path) -> Option<(String, IndexEntry)> {
+    let node = node_from_path(path)?;
+    let mtime = file_mtime(path)?;
+    let mut f = fs::File::open(path).ok()?;
+    let forward_links = read_wikilinks(&mut f).map(|(_, l)| l).collect();
+    let relative_path = path.strip_prefix(root).unwrap_or(path).to_path_buf();
+    Some((
+        node,
+        IndexEntry {
+            relative_path,
+            mtime,
+            forward_links,
+        },
+    ))
+}
+
+/// Builds a fresh index for the whole realm from scratch.
+pub fn build_index(root: &Path, walk_options: WalkOptions) -> Index {
+    let mut entries: Vec<(String, IndexEntry)> = realm_walker(root, walk_options)
+        .par_bridge()
+        .filter_map(|p| index_entry_for(root, &p))
+        .collect();
+
+    // `par_bridge()` does not preserve the walker's order, so sort by path
+    // before folding into the map: whichever of two files sharing a node
+    // stem "wins" must be deterministic. The entry with the later path wins;
+    // `find_node_once` instead takes the first match in the walker's own
+    // (unsorted) order, so the two can pick different files.
+    entries.sort_by(|(_, a), (_, b)| a.relative_path.cmp(&b.relative_path));
+
+    let mut index = Index::new();
+    for (node, entry) in entries {
+        insert_entry(&mut index, node, entry);
+    }
+    index
+}
+
+/// Inserts `entry` under `node`, warning if it collides with an entry
+/// already indexed under the same node (e.g. `note.md` and `note.txt`).
+fn insert_entry(index: &mut Index, node: String, entry: IndexEntry) {
+    let new_path = entry.relative_path.clone();
+    if let Some(existing) = index.insert(node.clone(), entry) {
+        warn!(
+            "node '{node}' is ambiguous between '{}' and '{}', indexing only the latter",
+            existing.relative_path.display(),
+            new_path.display()
+        );
+    }
+}
+
+/// Loads the persisted index, or an empty one if it doesn't exist yet.
+pub fn load_index(root: &Path) -> Index {
+    fs::read(index_path(root))
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+        .unwrap_or_default()
+}
+
+/// Writes the index back to `.nous/index`.
+pub fn save_index(root: &Path, index: &Index) {
+    let path = index_path(root);
+    let Ok(serialized) = serde_json::to_vec(index) else {
+        warn!("failed to serialize link index, skipping save");
+        return;
+    };
+    if fs::write(&path, serialized).is_err() {
+        warn!(
+            "failed to write link index to '{}'",
+            try_relative_path(&path).display()
+        )
+    }
+}
+
+/// Brings `index` up to date with the realm on disk: entries whose file is
+/// gone, or that no longer pass the current `walk_options`/`.nousignore`
+/// rules (e.g. a draft that's since been added to `.nousignore`, or one only
+/// ever indexed with `--hidden`), are dropped; entries whose mtime changed
+/// are reparsed; and files not yet present in the index are discovered via a
+/// `realm_walker` diff. Returns the refreshed index along with whether
+/// anything actually changed, so callers only need to write the index back
+/// out when it did.
+pub fn refresh_index(root: &Path, mut index: Index, walk_options: WalkOptions) -> (Index, bool) {
+    let mut changed = false;
+
+    let walked: HashSet<PathBuf> = realm_walker(root, walk_options)
+        .map(|p| p.strip_prefix(root).unwrap_or(&p).to_path_buf())
+        .collect();
+
+    let mut stale = vec![];
+    for (node, entry) in index.iter_mut() {
+        if !walked.contains(&entry.relative_path) {
+            stale.push(node.clone());
+            continue;
+        }
+        match file_mtime(&root.join(&entry.relative_path)) {
+            Some(mtime) if mtime == entry.mtime => {}
+            Some(mtime) => {
+                if let Ok(mut f) = fs::File::open(root.join(&entry.relative_path)) {
+                    entry.forward_links = read_wikilinks(&mut f).map(|(_, l)| l).collect();
+                    entry.mtime = mtime;
+                    changed = true;
+                }
+            }
+            None => stale.push(node.clone()),
+        }
+    }
+    for node in stale {
+        index.remove(&node);
+        changed = true;
+    }
+
+    let known: HashSet<PathBuf> = index.values().map(|e| e.relative_path.clone()).collect();
+    for relative_path in walked {
+        if known.contains(&relative_path) {
+            continue;
+        }
+        if let Some((node, entry)) = index_entry_for(root, &root.join(&relative_path)) {
+            insert_entry(&mut index, node, entry);
+            changed = true;
+        }
+    }
+
+    (index, changed)
+}
+
+/// Loads the index and brings it up to date, the way every index-backed
+/// query should start. Returns whether the refresh changed anything, so
+/// callers can skip writing the index back out when nothing did.
+pub fn current_index(root: &Path, walk_options: WalkOptions) -> (Index, bool) {
+    refresh_index(root, load_index(root), walk_options)
+}
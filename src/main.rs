@@ -1,19 +1,27 @@
 use crate::config::{
-    CLI_STYLE, DEFAULT_EXT, FALLBACK_EDITOR, FALLBACK_PAGER, ROOT_DIR_NAME, SUPPORTED_EXTS,
+    CLI_STYLE, DEFAULT_EXT, FALLBACK_EDITOR, FALLBACK_PAGER, IGNORE_FILE_NAME, ROOT_DIR_NAME,
+    SUPPORTED_EXTS,
 };
+use crate::settings::{node_matches, resolve_case_sensitivity, resolve_case_sensitivity_with};
 use crate::wikilinks::read_wikilinks;
 
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
+use ignore;
+use memchr;
 use pathdiff;
 use rayon::iter::{ParallelBridge, ParallelIterator};
+use serde::Serialize;
+use serde_json;
 use std::collections::BTreeSet;
+use std::io::{self, Cursor};
 use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant, SystemTime};
 use std::{env, fs, process};
-use walkdir;
 
 mod config;
 mod error_macros;
+mod index;
+mod settings;
 mod wikilinks;
 
 #[derive(Parser)]
@@ -23,6 +31,18 @@ mod wikilinks;
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+
+    /// Match node names case-sensitively
+    #[arg(long, global = true, conflicts_with = "ignore_case")]
+    case_sensitive: bool,
+
+    /// Match node names case-insensitively, regardless of the realm setting
+    #[arg(long, global = true)]
+    ignore_case: bool,
+
+    /// Include hidden (dot-prefixed) files and directories in the realm
+    #[arg(long, global = true)]
+    hidden: bool,
 }
 
 #[derive(Subcommand)]
@@ -98,6 +118,9 @@ enum Commands {
         /// Print the absolute path
         #[arg(short, long)]
         absolute: bool,
+        /// Print this node's subgraph in a machine-readable format
+        #[arg(long, value_enum)]
+        format: Option<OutputFormat>,
     },
 
     /// Rename a node, correcting backlinks
@@ -107,6 +130,9 @@ enum Commands {
         from: String,
         /// New node name
         to: String,
+        /// Overwrite an existing node at the destination
+        #[arg(short, long)]
+        force: bool,
     },
 
     /// Remove a node
@@ -140,7 +166,42 @@ enum Commands {
         /// Print the absolute path
         #[arg(short, long)]
         absolute: bool,
+        /// Print the realm graph in a machine-readable format
+        #[arg(long, value_enum)]
+        format: Option<OutputFormat>,
+    },
+
+    /// Find nodes by substring or */? glob against their name
+    Find {
+        /// Pattern to match node names against
+        pattern: String,
+        /// Print the path
+        #[arg(short, long)]
+        path: bool,
+        /// Print the absolute path
+        #[arg(short, long)]
+        absolute: bool,
+    },
+
+    /// Build or refresh the on-disk link index used by the backlink commands
+    Index,
+
+    /// Print the full realm link graph for downstream tooling
+    Graph {
+        /// Output format
+        #[arg(long, value_enum, default_value = "dot")]
+        format: OutputFormat,
     },
+
+    /// Report broken links and orphan nodes across the realm
+    #[command(visible_alias = "check")]
+    Doctor,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    Json,
+    Dot,
 }
 
 fn main() {
@@ -155,29 +216,113 @@ fn main() {
         error!("not within a νοῦς realm; you could use 'init' to create one")
     };
 
+    let case_sensitive =
+        |query: &str| resolve_case_sensitivity(&root, query, cli.case_sensitive, cli.ignore_case);
+    let walk_options = WalkOptions { hidden: cli.hidden };
+
     match &cli.command {
         Commands::Backlinks {
             node,
             path,
             absolute,
-        } => list_backlinks(&root, &node, *path, *absolute),
+        } => list_backlinks(
+            &root,
+            &node,
+            *path,
+            *absolute,
+            case_sensitive(node),
+            walk_options,
+        ),
         Commands::Forwardlinks {
             node,
             path,
             absolute,
-        } => list_forwardlinks(&root, &node, *path, *absolute),
+        } => list_forwardlinks(
+            &root,
+            &node,
+            *path,
+            *absolute,
+            case_sensitive(node),
+            walk_options,
+        ),
         Commands::Links {
             node,
             path,
             absolute,
-        } => list_links(&root, &node, *path, *absolute),
-        Commands::Move { from: _, to: _ } => todo!(),
-        Commands::Remove { node } => remove_node(&root, &node),
-        Commands::Edit { node, editor } => edit_node(&root, &node, editor.into()),
-        Commands::Open { node, pager } => open_node(&root, &node, pager.into()),
-        Commands::Touch { node } => touch_node(&root, &node),
-        Commands::Path { node, absolute } => path_to_node(&root, &node, *absolute),
-        Commands::List { path, absolute } => list_nodes(&root, *path, *absolute),
+            format,
+        } => list_links(
+            &root,
+            &node,
+            *path,
+            *absolute,
+            *format,
+            case_sensitive(node),
+            cli.case_sensitive,
+            cli.ignore_case,
+            walk_options,
+        ),
+        Commands::Move { from, to, force } => {
+            rename_node(
+                &root,
+                &from,
+                &to,
+                *force,
+                case_sensitive(from),
+                case_sensitive(to),
+                walk_options,
+            )
+        }
+        Commands::Remove { node } => {
+            remove_node(&root, &node, case_sensitive(node), walk_options)
+        }
+        Commands::Edit { node, editor } => {
+            edit_node(&root, &node, editor.into(), case_sensitive(node), walk_options)
+        }
+        Commands::Open { node, pager } => open_node(
+            &root,
+            &node,
+            pager.into(),
+            case_sensitive(node),
+            walk_options,
+        ),
+        Commands::Touch { node } => touch_node(&root, &node, case_sensitive(node), walk_options),
+        Commands::Path { node, absolute } => {
+            path_to_node(&root, &node, *absolute, case_sensitive(node), walk_options)
+        }
+        Commands::List {
+            path,
+            absolute,
+            format,
+        } => list_nodes(
+            &root,
+            *path,
+            *absolute,
+            *format,
+            cli.case_sensitive,
+            cli.ignore_case,
+            walk_options,
+        ),
+        Commands::Find {
+            pattern,
+            path,
+            absolute,
+        } => find_nodes(
+            &root,
+            pattern,
+            *path,
+            *absolute,
+            case_sensitive(pattern),
+            walk_options,
+        ),
+        Commands::Index => build_index(&root, walk_options),
+        Commands::Graph { format } => print_graph(
+            &root,
+            *format,
+            cli.case_sensitive,
+            cli.ignore_case,
+            walk_options,
+        ),
+        Commands::Doctor => doctor_realm(&root, cli.case_sensitive, cli.ignore_case, walk_options),
         Commands::Root { absolute } => println_path(&root, *absolute),
         Commands::Init { root: _ } => unreachable!(),
     }
@@ -203,55 +348,270 @@ fn init_realm(target: &Path) {
     }
 }
 
-fn list_forwardlinks(root: &Path, node: &String, path: bool, absolute: bool) {
-    if let Some(node_path) = find_node_once(root, node, false) {
-        let Ok(mut f) = fs::File::open(node_path) else {
-            error!("failed to open file of '{node}'")
-        };
-        for link in read_wikilinks(&mut f)
-            .map(|(_, l)| l)
-            .collect::<BTreeSet<_>>()
-        {
-            if absolute || path {
-                match find_node_once(root, &link, false) {
-                    Some(path) => println_path(&path, absolute),
-                    None => warn!("no file found for '{link}'"),
-                }
-            } else {
-                println!("{link}")
+fn list_forwardlinks(
+    root: &Path,
+    node: &String,
+    path: bool,
+    absolute: bool,
+    case_sensitive: bool,
+    walk_options: WalkOptions,
+) {
+    let (idx, changed) = index::current_index(root, walk_options);
+    list_forwardlinks_from(root, &idx, node, path, absolute, case_sensitive);
+    if changed {
+        index::save_index(root, &idx);
+    }
+}
+
+/// Prints forward links for `node`, reading from an already-refreshed
+/// `idx` rather than loading/refreshing one of its own. Shared by
+/// `list_forwardlinks` and `list_links`, so a single `nous links`
+/// invocation only pays for one index load.
+fn list_forwardlinks_from(
+    root: &Path,
+    idx: &index::Index,
+    node: &String,
+    path: bool,
+    absolute: bool,
+    case_sensitive: bool,
+) {
+    let Some(entry) = idx.iter().find(|(n, _)| node_matches(n, node, case_sensitive)) else {
+        return;
+    };
+
+    for link in entry.1.forward_links.iter().collect::<BTreeSet<_>>() {
+        if absolute || path {
+            match idx.iter().find(|(n, _)| node_matches(n, link, case_sensitive)) {
+                Some((_, target)) => println_path(&root.join(&target.relative_path), absolute),
+                None => warn!("no file found for '{link}'"),
             }
+        } else {
+            println!("{link}")
         }
     }
 }
 
-fn list_backlinks(root: &Path, node: &String, path: bool, absolute: bool) {
-    realm_walker(root)
-        .par_bridge()
-        .filter(|p| {
-            fs::File::open(p).is_ok_and(|mut f| {
-                read_wikilinks(&mut f).any(|(_, l)| node.eq_ignore_ascii_case(&l))
-            })
-        })
-        .for_each(|bl| {
+fn list_backlinks(
+    root: &Path,
+    node: &String,
+    path: bool,
+    absolute: bool,
+    case_sensitive: bool,
+    walk_options: WalkOptions,
+) {
+    let (idx, changed) = index::current_index(root, walk_options);
+    list_backlinks_from(root, &idx, node, path, absolute, case_sensitive);
+    if changed {
+        index::save_index(root, &idx);
+    }
+}
+
+/// Prints backlinks to `node`, reading from an already-refreshed `idx`
+/// rather than loading/refreshing one of its own. Shared by
+/// `list_backlinks` and `list_links`, so a single `nous links`
+/// invocation only pays for one index load.
+fn list_backlinks_from(
+    root: &Path,
+    idx: &index::Index,
+    node: &String,
+    path: bool,
+    absolute: bool,
+    case_sensitive: bool,
+) {
+    for (n, entry) in idx {
+        if entry
+            .forward_links
+            .iter()
+            .any(|l| node_matches(l, node, case_sensitive))
+        {
             if absolute || path {
-                println_path(&bl, absolute)
+                println_path(&root.join(&entry.relative_path), absolute)
             } else {
-                println_node(&bl)
+                println!("{n}")
             }
-        });
+        }
+    }
+}
+
+fn build_index(root: &Path, walk_options: WalkOptions) {
+    let idx = index::build_index(root, walk_options);
+    let count = idx.len();
+    index::save_index(root, &idx);
+    warn!("indexed {count} node(s)");
 }
 
-fn list_links(root: &Path, node: &String, path: bool, absolute: bool) {
+fn list_links(
+    root: &Path,
+    node: &String,
+    path: bool,
+    absolute: bool,
+    format: Option<OutputFormat>,
+    case_sensitive: bool,
+    cli_case_sensitive: bool,
+    cli_ignore_case: bool,
+    walk_options: WalkOptions,
+) {
+    if let Some(format) = format {
+        let graph = build_graph(root, cli_case_sensitive, cli_ignore_case, walk_options);
+        let neighborhood = graph
+            .iter()
+            .filter(|e| {
+                node_matches(&e.node, node, case_sensitive)
+                    || e.forward_links.iter().any(|l| node_matches(l, node, case_sensitive))
+                    || graph.iter().any(|o| {
+                        node_matches(&o.node, node, case_sensitive)
+                            && o.forward_links
+                                .iter()
+                                .any(|l| node_matches(l, &e.node, case_sensitive))
+                    })
+            })
+            .map(|e| {
+                // Only edges touching the queried node belong in its
+                // subgraph; an included neighbor's links to unrelated
+                // third-party nodes are not part of this neighborhood.
+                let is_query = node_matches(&e.node, node, case_sensitive);
+                GraphEntry {
+                    node: e.node.clone(),
+                    path: e.path.clone(),
+                    forward_links: e
+                        .forward_links
+                        .iter()
+                        .filter(|l| is_query || node_matches(l, node, case_sensitive))
+                        .cloned()
+                        .collect(),
+                }
+            })
+            .collect::<Vec<_>>();
+        emit_graph(&neighborhood, format);
+        return;
+    }
+
+    let (idx, changed) = index::current_index(root, walk_options);
+
     let style = CLI_STYLE.get_header();
     println!("{style}Backlinks:{style:#}");
-    list_backlinks(root, node, path, absolute);
+    list_backlinks_from(root, &idx, node, path, absolute, case_sensitive);
 
     println!("\n{style}Forward links:{style:#}");
-    list_forwardlinks(root, node, path, absolute);
+    list_forwardlinks_from(root, &idx, node, path, absolute, case_sensitive);
+
+    if changed {
+        index::save_index(root, &idx);
+    }
+}
+
+fn rename_node(
+    root: &Path,
+    from: &String,
+    to: &String,
+    force: bool,
+    case_sensitive: bool,
+    to_case_sensitive: bool,
+    walk_options: WalkOptions,
+) {
+    let Some(from_path) = find_node_once(root, from, true, case_sensitive, walk_options) else {
+        error!("node '{from}' does not exist")
+    };
+
+    let existing_to = find_node_once(root, to, false, to_case_sensitive, walk_options);
+
+    let ext = from_path
+        .extension()
+        .map_or(DEFAULT_EXT.to_string(), |e| e.to_string_lossy().to_string());
+    let to_path = default_file_name(root, to).with_extension(ext);
+
+    // Check the literal write target as well as `existing_to`: when `to` is
+    // ambiguous (e.g. it case-insensitively matches both `from_path` and an
+    // unrelated file), `find_node_once`'s "only using first" fallback can
+    // return `from_path` itself, making a from_path-based check conclude
+    // "no collision" even though a different file already sits at `to_path`.
+    if !force
+        && ((to_path != from_path && to_path.exists())
+            || existing_to.as_ref().is_some_and(|p| *p != from_path))
+    {
+        error!("node '{to}' already exists, use --force to rename into it anyway")
+    }
+
+    // A pre-existing destination node may live under a different extension
+    // than `to_path`; remove it so `--force` actually takes over the node
+    // instead of leaving a stale duplicate file behind.
+    if let Some(existing_path) = existing_to {
+        if existing_path != from_path && existing_path != to_path {
+            fs::remove_file(&existing_path).unwrap_or_else(|_| {
+                error!(
+                    "failed to remove existing node at '{}' to make way for '{to}'",
+                    try_relative_path(&existing_path).display()
+                )
+            });
+        }
+    }
+
+    fs::rename(&from_path, &to_path).unwrap_or_else(|_| {
+        error!(
+            "failed to rename '{}' to '{}'",
+            try_relative_path(&from_path).display(),
+            try_relative_path(&to_path).display()
+        )
+    });
+
+    let mut updated = 0usize;
+    for p in realm_walker(root, walk_options) {
+        match rewrite_wikilinks(&p, from, to, case_sensitive) {
+            Ok(true) => updated += 1,
+            Ok(false) => {}
+            Err(_) => warn!(
+                "failed to update links in '{}'",
+                try_relative_path(&p).display()
+            ),
+        }
+    }
+    warn!("updated backlinks in {updated} file(s)");
 }
 
-fn remove_node(root: &Path, node: &String) {
-    match find_node_once(root, node, true) {
+// Rewrites every [[from]] reference in the file at path to [[to]], preserving
+// any |alias or #anchor tail. Returns whether the file was changed, and
+// leaves files with no matches untouched so their mtime is not bumped.
+fn rewrite_wikilinks(path: &Path, from: &str, to: &str, case_sensitive: bool) -> io::Result<bool> {
+    let contents = fs::read(path)?;
+
+    let mut spans = vec![];
+    for (idx, target) in read_wikilinks(Cursor::new(contents.as_slice())) {
+        if node_matches(&target, from, case_sensitive) {
+            let start = idx as usize + 2;
+            // Bound the search by the real closing `]]`, not the first lone
+            // `]`, so a target containing a literal `]` (e.g. `[[a]b]]`)
+            // doesn't get its replacement span truncated early.
+            let closing = wikilinks::find_closing_tag(&contents, start).unwrap_or(contents.len());
+            let end = start
+                + memchr::memchr2(b'|', b'#', &contents[start..closing])
+                    .unwrap_or(closing - start);
+            spans.push((start, end));
+        }
+    }
+    if spans.is_empty() {
+        return Ok(false);
+    }
+
+    let mut rewritten = Vec::with_capacity(contents.len());
+    let mut last = 0;
+    for (start, end) in spans {
+        rewritten.extend_from_slice(&contents[last..start]);
+        rewritten.extend_from_slice(to.as_bytes());
+        last = end;
+    }
+    rewritten.extend_from_slice(&contents[last..]);
+
+    let mut tmp_name = path.file_name().unwrap_or_default().to_os_string();
+    tmp_name.push(".nous-tmp");
+    let tmp_path = path.with_file_name(tmp_name);
+    fs::write(&tmp_path, &rewritten)?;
+    fs::rename(&tmp_path, path)?;
+
+    Ok(true)
+}
+
+fn remove_node(root: &Path, node: &String, case_sensitive: bool, walk_options: WalkOptions) {
+    match find_node_once(root, node, true, case_sensitive, walk_options) {
         Some(path) => fs::remove_file(&path).unwrap_or_else(|_| {
             error!(
                 "failed to remove node at '{}'",
@@ -262,8 +622,15 @@ fn remove_node(root: &Path, node: &String) {
     }
 }
 
-fn edit_node(root: &Path, node: &String, editor: Option<&String>) {
-    let path = find_node_once(root, node, false).unwrap_or(default_file_name(root, node));
+fn edit_node(
+    root: &Path,
+    node: &String,
+    editor: Option<&String>,
+    case_sensitive: bool,
+    walk_options: WalkOptions,
+) {
+    let path = find_node_once(root, node, false, case_sensitive, walk_options)
+        .unwrap_or(default_file_name(root, node));
 
     let editor = editor
         .cloned()
@@ -294,8 +661,14 @@ fn edit_node(root: &Path, node: &String, editor: Option<&String>) {
     }
 }
 
-fn open_node(root: &Path, node: &String, pager: Option<&String>) {
-    let Some(path) = find_node_once(root, node, false) else {
+fn open_node(
+    root: &Path,
+    node: &String,
+    pager: Option<&String>,
+    case_sensitive: bool,
+    walk_options: WalkOptions,
+) {
+    let Some(path) = find_node_once(root, node, false, case_sensitive, walk_options) else {
         error!("node does not exist")
     };
 
@@ -327,8 +700,9 @@ fn open_node(root: &Path, node: &String, pager: Option<&String>) {
     }
 }
 
-fn touch_node(root: &Path, node: &String) {
-    let path = find_node_once(root, node, false).unwrap_or(default_file_name(root, node));
+fn touch_node(root: &Path, node: &String, case_sensitive: bool, walk_options: WalkOptions) {
+    let path = find_node_once(root, node, false, case_sensitive, walk_options)
+        .unwrap_or(default_file_name(root, node));
     if path.file_name().is_some() && path.parent().map_or(true, |p| p.is_dir()) {
         let Ok(file) = fs::OpenOptions::new().create(true).write(true).open(&path) else {
             error!(
@@ -342,15 +716,102 @@ fn touch_node(root: &Path, node: &String) {
     }
 }
 
-fn path_to_node(root: &Path, node: &String, absolute: bool) {
-    match find_node_once(root, node, false) {
+fn path_to_node(
+    root: &Path,
+    node: &String,
+    absolute: bool,
+    case_sensitive: bool,
+    walk_options: WalkOptions,
+) {
+    match find_node_once(root, node, false, case_sensitive, walk_options) {
         Some(path) => println_path(&path, absolute),
         None => warn!("node not found"),
     }
 }
 
-fn list_nodes(root: &Path, path: bool, absolute: bool) {
-    for p in realm_walker(root) {
+fn find_nodes(
+    root: &Path,
+    pattern: &str,
+    path: bool,
+    absolute: bool,
+    case_sensitive: bool,
+    walk_options: WalkOptions,
+) {
+    for p in realm_walker(root, walk_options) {
+        let Some(node) = node_from_path(&p) else {
+            continue;
+        };
+        if glob_match(pattern, &node, case_sensitive) {
+            if path || absolute {
+                println_path(&p, absolute)
+            } else {
+                println!("{node}")
+            }
+        }
+    }
+}
+
+// Matches `text` against `pattern`: a plain substring match if `pattern`
+// contains no glob characters, otherwise a `*`/`?` glob match.
+fn glob_match(pattern: &str, text: &str, case_sensitive: bool) -> bool {
+    let fold = |s: &str| {
+        if case_sensitive {
+            s.to_string()
+        } else {
+            s.to_ascii_lowercase()
+        }
+    };
+    let pattern = fold(pattern);
+    let text = fold(text);
+
+    if !pattern.contains(['*', '?']) {
+        return text.contains(&pattern);
+    }
+    glob_match_bytes(pattern.as_bytes(), text.as_bytes())
+}
+
+// Iterative two-pointer wildcard match: remembers the most recent `*` and how
+// much of `text` it has consumed so far, backtracking to that point on a
+// mismatch instead of trying both branches recursively (which is
+// exponential on patterns like `*a*a*a*...`).
+fn glob_match_bytes(pattern: &[u8], text: &[u8]) -> bool {
+    let (mut pi, mut ti) = (0usize, 0usize);
+    let mut star: Option<(usize, usize)> = None;
+
+    while ti < text.len() {
+        if pi < pattern.len() && (pattern[pi] == b'?' || pattern[pi] == text[ti]) {
+            pi += 1;
+            ti += 1;
+        } else if pi < pattern.len() && pattern[pi] == b'*' {
+            star = Some((pi, ti));
+            pi += 1;
+        } else if let Some((star_pi, star_ti)) = star {
+            pi = star_pi + 1;
+            ti = star_ti + 1;
+            star = Some((star_pi, ti));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[pi..].iter().all(|&b| b == b'*')
+}
+
+fn list_nodes(
+    root: &Path,
+    path: bool,
+    absolute: bool,
+    format: Option<OutputFormat>,
+    cli_case_sensitive: bool,
+    cli_ignore_case: bool,
+    walk_options: WalkOptions,
+) {
+    if let Some(format) = format {
+        print_graph(root, format, cli_case_sensitive, cli_ignore_case, walk_options);
+        return;
+    }
+
+    for p in realm_walker(root, walk_options) {
         if path || absolute {
             println_path(&p, absolute)
         } else {
@@ -359,6 +820,229 @@ fn list_nodes(root: &Path, path: bool, absolute: bool) {
     }
 }
 
+#[derive(Debug, Clone, Serialize)]
+struct GraphEntry {
+    node: String,
+    path: String,
+    forward_links: Vec<String>,
+}
+
+// One parallel pass over the realm building the full link graph, including
+// dangling targets (links with no matching file) as edge-less nodes.
+fn build_graph(
+    root: &Path,
+    cli_case_sensitive: bool,
+    cli_ignore_case: bool,
+    walk_options: WalkOptions,
+) -> Vec<GraphEntry> {
+    let direct: Vec<(String, PathBuf, Vec<String>)> = realm_walker(root, walk_options)
+        .par_bridge()
+        .filter_map(|p| {
+            let node = node_from_path(&p)?;
+            let mut f = fs::File::open(&p).ok()?;
+            let forward_links = read_wikilinks(&mut f).map(|(_, l)| l).collect();
+            Some((node, p, forward_links))
+        })
+        .collect();
+
+    // Settings are loaded once up front, the same way `doctor_realm` does
+    // it, so resolving each target's case-sensitivity is a pure in-memory
+    // computation rather than a `.nous/config` read per comparison.
+    let settings = settings::load(root);
+    let resolve = |query: &str| {
+        resolve_case_sensitivity_with(&settings, query, cli_case_sensitive, cli_ignore_case)
+    };
+
+    let exact_names = direct
+        .iter()
+        .map(|(n, _, _)| n.clone())
+        .collect::<BTreeSet<_>>();
+    let lower_names = direct
+        .iter()
+        .map(|(n, _, _)| n.to_ascii_lowercase())
+        .collect::<BTreeSet<_>>();
+    let resolves = |target: &str| {
+        if resolve(target) {
+            exact_names.contains(target)
+        } else {
+            lower_names.contains(&target.to_ascii_lowercase())
+        }
+    };
+
+    let dangling = direct
+        .iter()
+        .flat_map(|(_, _, links)| links)
+        .filter(|l| !resolves(l))
+        .cloned()
+        .collect::<BTreeSet<_>>();
+
+    let mut entries = direct
+        .into_iter()
+        .map(|(node, path, forward_links)| GraphEntry {
+            node,
+            path: try_relative_path(&path).display().to_string(),
+            forward_links,
+        })
+        .collect::<Vec<_>>();
+    entries.extend(dangling.into_iter().map(|node| GraphEntry {
+        node,
+        path: String::new(),
+        forward_links: vec![],
+    }));
+    entries.sort_by(|a, b| a.node.to_ascii_lowercase().cmp(&b.node.to_ascii_lowercase()));
+    entries
+}
+
+fn print_graph(
+    root: &Path,
+    format: OutputFormat,
+    cli_case_sensitive: bool,
+    cli_ignore_case: bool,
+    walk_options: WalkOptions,
+) {
+    emit_graph(
+        &build_graph(root, cli_case_sensitive, cli_ignore_case, walk_options),
+        format,
+    );
+}
+
+fn emit_graph(entries: &[GraphEntry], format: OutputFormat) {
+    match format {
+        OutputFormat::Json => match serde_json::to_string(entries) {
+            Ok(json) => println!("{json}"),
+            Err(_) => error!("failed to serialize graph to JSON"),
+        },
+        OutputFormat::Dot => {
+            println!("digraph {{");
+            for e in entries {
+                if e.forward_links.is_empty() {
+                    println!("    \"{}\";", escape_dot(&e.node));
+                } else {
+                    for link in &e.forward_links {
+                        println!(
+                            "    \"{}\" -> \"{}\";",
+                            escape_dot(&e.node),
+                            escape_dot(link)
+                        );
+                    }
+                }
+            }
+            println!("}}");
+        }
+    }
+}
+
+// One parallel pass over the realm collecting, per node, its forward links
+// together with the byte offset at which each was found, then reports
+// broken links (targets with no resolvable file) and orphan nodes (files
+// that neither link out nor receive a backlink).
+fn doctor_realm(
+    root: &Path,
+    cli_case_sensitive: bool,
+    cli_ignore_case: bool,
+    walk_options: WalkOptions,
+) {
+    let direct: Vec<(String, PathBuf, Vec<(u64, String)>)> = realm_walker(root, walk_options)
+        .par_bridge()
+        .filter_map(|p| {
+            let node = node_from_path(&p)?;
+            let mut f = fs::File::open(&p).ok()?;
+            let links = read_wikilinks(&mut f).collect();
+            Some((node, p, links))
+        })
+        .collect();
+
+    // Settings are loaded once up front so resolving case-sensitivity per
+    // target is a pure, in-memory computation rather than a `.nous/config`
+    // read per comparison.
+    let settings = settings::load(root);
+    let resolve = |query: &str| {
+        resolve_case_sensitivity_with(&settings, query, cli_case_sensitive, cli_ignore_case)
+    };
+
+    // Node names are bucketed once so resolving a target against "does a
+    // matching node exist" is an O(log V) set lookup instead of an O(V) scan.
+    let exact_names = direct
+        .iter()
+        .map(|(n, _, _)| n.clone())
+        .collect::<BTreeSet<_>>();
+    let lower_names = direct
+        .iter()
+        .map(|(n, _, _)| n.to_ascii_lowercase())
+        .collect::<BTreeSet<_>>();
+    let resolves = |target: &str| {
+        if resolve(target) {
+            exact_names.contains(target)
+        } else {
+            lower_names.contains(&target.to_ascii_lowercase())
+        }
+    };
+
+    let mut broken = direct
+        .iter()
+        .filter_map(|(node, path, links)| {
+            let dangling = links
+                .iter()
+                .filter(|(_, t)| !resolves(t))
+                .cloned()
+                .collect::<Vec<_>>();
+            (!dangling.is_empty()).then(|| (node.clone(), path.clone(), dangling))
+        })
+        .collect::<Vec<_>>();
+    broken.sort_by(|a, b| a.0.to_ascii_lowercase().cmp(&b.0.to_ascii_lowercase()));
+
+    // Likewise, every link target is bucketed by its resolved
+    // case-sensitivity once, so checking whether a node is linked-to is an
+    // O(log E) lookup instead of an O(E) scan per node.
+    let all_targets = direct.iter().flat_map(|(_, _, links)| links.iter().map(|(_, t)| t));
+    let mut linked_exact = BTreeSet::new();
+    let mut linked_lower = BTreeSet::new();
+    for target in all_targets {
+        if resolve(target) {
+            linked_exact.insert(target.clone());
+        } else {
+            linked_lower.insert(target.to_ascii_lowercase());
+        }
+    }
+    let is_linked =
+        |node: &str| linked_exact.contains(node) || linked_lower.contains(&node.to_ascii_lowercase());
+
+    let mut orphans = direct
+        .iter()
+        .filter(|(node, _, links)| links.is_empty() && !is_linked(node))
+        .map(|(node, _, _)| node.clone())
+        .collect::<Vec<_>>();
+    orphans.sort_by_key(|n| n.to_ascii_lowercase());
+
+    let style = CLI_STYLE.get_header();
+    println!("{style}Broken links:{style:#}");
+    if broken.is_empty() {
+        println!("  none");
+    }
+    for (node, path, dangling) in &broken {
+        println!("  {node} ({})", try_relative_path(path).display());
+        for (offset, target) in dangling {
+            println!("    @{offset}: [[{target}]]");
+        }
+    }
+
+    println!("\n{style}Orphan nodes:{style:#}");
+    if orphans.is_empty() {
+        println!("  none");
+    }
+    for node in &orphans {
+        println!("  {node}");
+    }
+
+    if !broken.is_empty() {
+        process::exit(1);
+    }
+}
+
+fn escape_dot(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
 fn println_path(path: &Path, absolute: bool) {
     if absolute {
         println!("{}", try_absolute_path(path).display())
@@ -410,19 +1094,29 @@ pub fn println_node(path: &Path) {
     }
 }
 
-pub fn realm_walker(root: &Path) -> impl Iterator<Item = PathBuf> {
-    fn is_hidden(entry: &walkdir::DirEntry) -> bool {
-        entry
-            .file_name()
-            .to_str()
-            .map(|s| s.starts_with("."))
-            .unwrap_or(false)
-    }
+/// Shared view of which files belong to the realm, consulted by every
+/// traversal (`list_nodes`, `list_backlinks`, `find_node`, ...).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WalkOptions {
+    /// Include dotfiles/dot-directories instead of skipping them.
+    pub hidden: bool,
+}
 
-    walkdir::WalkDir::new(root)
+pub fn realm_walker(root: &Path, options: WalkOptions) -> impl Iterator<Item = PathBuf> {
+    ignore::WalkBuilder::new(root)
         .follow_links(true)
-        .into_iter()
-        .filter_entry(|e| !is_hidden(e))
+        .hidden(!options.hidden)
+        .parents(false)
+        .git_ignore(false)
+        .git_global(false)
+        .git_exclude(false)
+        .ignore(false)
+        .add_custom_ignore_filename(IGNORE_FILE_NAME)
+        // `.nous` is the realm's own metadata directory, not a user note;
+        // `--hidden` is meant to surface dot-prefixed notes, so it must
+        // still be excluded unconditionally.
+        .filter_entry(|e| e.file_name() != ROOT_DIR_NAME)
+        .build()
         .filter_map(|e| e.ok())
         .map(|e| e.into_path())
         .filter(|p| {
@@ -432,17 +1126,28 @@ pub fn realm_walker(root: &Path) -> impl Iterator<Item = PathBuf> {
         })
 }
 
-fn find_node(root: &Path, node: &String) -> impl Iterator<Item = PathBuf> {
+fn find_node(
+    root: &Path,
+    node: &String,
+    case_sensitive: bool,
+    walk_options: WalkOptions,
+) -> impl Iterator<Item = PathBuf> {
     let node = node.clone();
-    realm_walker(root).filter(move |p| {
+    realm_walker(root, walk_options).filter(move |p| {
         p.file_stem()
-            .map_or(false, |s| s.eq_ignore_ascii_case(&node))
+            .map_or(false, |s| node_matches(&s.to_string_lossy(), &node, case_sensitive))
             && p.is_file()
     })
 }
 
-pub fn find_node_once(root: &Path, node: &String, strict: bool) -> Option<PathBuf> {
-    let mut matcher = find_node(root, node);
+pub fn find_node_once(
+    root: &Path,
+    node: &String,
+    strict: bool,
+    case_sensitive: bool,
+    walk_options: WalkOptions,
+) -> Option<PathBuf> {
+    let mut matcher = find_node(root, node, case_sensitive, walk_options);
     let result = matcher.next();
     if let Some(_) = matcher.next() {
         if strict {
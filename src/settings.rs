@@ -0,0 +1,92 @@
+use crate::config::ROOT_DIR_NAME;
+
+use std::fs;
+use std::path::Path;
+
+const SETTINGS_FILE_NAME: &str = "config";
+
+/// Realm-wide node matching mode. `Smart` (the default) matches
+/// case-insensitively unless the query itself contains an uppercase
+/// character, mirroring the smart-case convention of tools like `rg`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CaseSensitivity {
+    #[default]
+    Smart,
+    Sensitive,
+    Insensitive,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RealmSettings {
+    pub case_sensitivity: CaseSensitivity,
+}
+
+/// Loads `.nous/config`, falling back to defaults for any setting that is
+/// missing or if the file does not exist at all.
+pub fn load(root: &Path) -> RealmSettings {
+    let mut settings = RealmSettings::default();
+
+    let Ok(contents) = fs::read_to_string(root.join(ROOT_DIR_NAME).join(SETTINGS_FILE_NAME))
+    else {
+        return settings;
+    };
+
+    for line in contents.lines() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        if key.trim() == "case_sensitivity" {
+            settings.case_sensitivity = match value.trim() {
+                "sensitive" => CaseSensitivity::Sensitive,
+                "insensitive" => CaseSensitivity::Insensitive,
+                _ => CaseSensitivity::Smart,
+            };
+        }
+    }
+
+    settings
+}
+
+/// Resolves whether matching should be case-sensitive for `query`, given the
+/// CLI overrides, falling back to the realm setting and its smart-case
+/// default.
+pub fn resolve_case_sensitivity(
+    root: &Path,
+    query: &str,
+    cli_case_sensitive: bool,
+    cli_ignore_case: bool,
+) -> bool {
+    resolve_case_sensitivity_with(&load(root), query, cli_case_sensitive, cli_ignore_case)
+}
+
+/// Same as [`resolve_case_sensitivity`], but against an already-loaded
+/// [`RealmSettings`] so callers resolving many queries don't re-read
+/// `.nous/config` from disk for each one.
+pub fn resolve_case_sensitivity_with(
+    settings: &RealmSettings,
+    query: &str,
+    cli_case_sensitive: bool,
+    cli_ignore_case: bool,
+) -> bool {
+    if cli_case_sensitive {
+        return true;
+    }
+    if cli_ignore_case {
+        return false;
+    }
+    match settings.case_sensitivity {
+        CaseSensitivity::Sensitive => true,
+        CaseSensitivity::Insensitive => false,
+        CaseSensitivity::Smart => query.chars().any(|c| c.is_uppercase()),
+    }
+}
+
+/// Compares a node name against a query, honoring `case_sensitive`.
+pub fn node_matches(candidate: &str, query: &str, case_sensitive: bool) -> bool {
+    if case_sensitive {
+        candidate == query
+    } else {
+        candidate.eq_ignore_ascii_case(query)
+    }
+}
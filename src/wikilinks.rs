@@ -51,6 +51,22 @@ fn extract_link_target(mut buf: Vec<u8>) -> Result<String, string::FromUtf8Error
     String::from_utf8(buf.trim_ascii().to_vec())
 }
 
+// Finds the offset of the first `]]` pair at or after `start`, the same way
+// `read_to_closing_tag` does over a reader: a lone `]` that isn't
+// immediately followed by another `]` is just part of the target (e.g. the
+// `]` in `[[a]b]]`) and doesn't end the tag. Returns `None` if `contents`
+// has no closing tag at or after `start`.
+pub fn find_closing_tag(contents: &[u8], start: usize) -> Option<usize> {
+    let mut i = start;
+    loop {
+        let pos = i + memchr::memchr(b']', &contents[i..])?;
+        if contents.get(pos + 1) == Some(&b']') {
+            return Some(pos);
+        }
+        i = pos + 1;
+    }
+}
+
 pub fn next_wikilink<R: io::BufRead + io::Seek>(r: &mut R) -> Option<(u64, String)> {
     // Keep going until we find a link which is not internal, or an error occurs
     loop {